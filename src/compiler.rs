@@ -0,0 +1,140 @@
+use crate::fsm::FSM;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+};
+
+/// Renders `fsm` as a standalone Rust source file with no dependency on this crate.
+///
+/// The generated module exposes a `State` enum, a `step` function mirroring the FSM's
+/// transition table, and a `run` function that folds `step` over a string and reports
+/// whether the final state is an accept state.
+pub fn generate(fsm: &FSM) -> String {
+    let mut state_names: BTreeMap<String, bool> = BTreeMap::new();
+    state_names.insert(
+        fsm.start_state().name().to_string(),
+        fsm.start_state().is_accept(),
+    );
+    for transition in fsm.transitions() {
+        state_names.insert(
+            transition.start_state().name().to_string(),
+            transition.start_state().is_accept(),
+        );
+        state_names.insert(
+            transition.end_state().name().to_string(),
+            transition.end_state().is_accept(),
+        );
+    }
+
+    let idents = sanitize_idents(state_names.keys());
+
+    let mut out = String::new();
+    writeln!(out, "// Generated by `fsm compile`; do not edit by hand.").unwrap();
+    writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+    writeln!(out, "pub enum State {{").unwrap();
+    for name in state_names.keys() {
+        writeln!(out, "    {},", idents[name]).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(out, "pub fn step(state: State, c: char) -> Option<State> {{").unwrap();
+    writeln!(out, "    match (state, c) {{").unwrap();
+    let mut transitions: Vec<_> = fsm.transitions().iter().collect();
+    transitions.sort_by_key(|t| (t.start_state().name().to_string(), t.input()));
+    for transition in transitions {
+        writeln!(
+            out,
+            "        (State::{}, {:?}) => Some(State::{}),",
+            idents[transition.start_state().name()],
+            transition.input(),
+            idents[transition.end_state().name()],
+        )
+        .unwrap();
+    }
+    writeln!(out, "        _ => None,").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(out, "pub fn run(input: &str) -> bool {{").unwrap();
+    writeln!(
+        out,
+        "    let mut state = State::{};",
+        idents[fsm.start_state().name()]
+    )
+    .unwrap();
+    writeln!(out, "    for c in input.chars() {{").unwrap();
+    writeln!(out, "        state = match step(state, c) {{").unwrap();
+    writeln!(out, "            Some(next) => next,").unwrap();
+    writeln!(out, "            None => return false,").unwrap();
+    writeln!(out, "        }};").unwrap();
+    writeln!(out, "    }}").unwrap();
+
+    let accept_idents: Vec<&str> = state_names
+        .iter()
+        .filter(|(_, is_accept)| **is_accept)
+        .map(|(name, _)| idents[name].as_str())
+        .collect();
+    if accept_idents.is_empty() {
+        writeln!(out, "    false").unwrap();
+    } else {
+        let pattern = accept_idents
+            .iter()
+            .map(|ident| format!("State::{}", ident))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        writeln!(out, "    matches!(state, {})", pattern).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    out
+}
+
+/// Maps each state name to a valid, unique Rust identifier, resolving collisions
+/// deterministically by appending an incrementing suffix in name order.
+fn sanitize_idents<'a, I>(names: I) -> BTreeMap<String, String>
+where
+    I: IntoIterator<Item = &'a String>,
+{
+    let mut idents = BTreeMap::new();
+    let mut used: BTreeSet<String> = BTreeSet::new();
+
+    for name in names {
+        let base = sanitize_ident(name);
+        let mut ident = base.clone();
+        let mut suffix = 1;
+        while used.contains(&ident) {
+            suffix += 1;
+            ident = format!("{}_{}", base, suffix);
+        }
+        used.insert(ident.clone());
+        idents.insert(name.clone(), ident);
+    }
+
+    idents
+}
+
+/// Rust keywords (strict and reserved, 2015 through 2021) that are not
+/// themselves valid identifiers.
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final",
+    "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "try", "union",
+];
+
+fn sanitize_ident(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    if ident.is_empty() || ident.chars().next().unwrap().is_ascii_digit() {
+        ident.insert(0, '_');
+    }
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+    ident
+}
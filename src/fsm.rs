@@ -49,15 +49,40 @@ impl State {
             Self::AcceptState(name) => name.to_owned(),
         }
     }
+
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            Self::State(name) => name,
+            Self::AcceptState(name) => name,
+        }
+    }
+
+    pub(crate) fn is_accept(&self) -> bool {
+        matches!(self, Self::AcceptState(_))
+    }
 }
 
 #[derive(Debug, Eq, Clone)]
-struct Transition {
+pub(crate) struct Transition {
     input: char,
     start_state: State,
     end_state: State,
 }
 
+impl Transition {
+    pub(crate) fn input(&self) -> char {
+        self.input
+    }
+
+    pub(crate) fn start_state(&self) -> &State {
+        &self.start_state
+    }
+
+    pub(crate) fn end_state(&self) -> &State {
+        &self.end_state
+    }
+}
+
 impl PartialEq for Transition {
     fn eq(&self, other: &Self) -> bool {
         self.input == other.input && self.start_state == other.start_state
@@ -101,6 +126,18 @@ impl FSM {
             State::AcceptState(_) => true,
         })
     }
+
+    pub(crate) fn start_state(&self) -> &State {
+        &self.start_state
+    }
+
+    pub(crate) fn transitions(&self) -> &HashSet<Transition> {
+        &self.transitions
+    }
+
+    pub(crate) fn input_alphabet(&self) -> &HashSet<char> {
+        &self.input_alphabet
+    }
 }
 
 #[derive(Debug)]
@@ -150,51 +187,84 @@ pub fn validate_parsed_fsm(parsed_fsm: ParsedFSM) -> Result<FSM, FSMError> {
         };
     }
 
-    let mut input_alphabet: HashSet<char> = HashSet::new();
+    // Expand `|`-alternated transitions into one (char, transition) pair per
+    // listed character; wildcard transitions are kept aside as a lower
+    // precedence fallback applied once the explicit edges are exhausted.
+    let mut explicit: Vec<(char, &ParsedTransition)> = Vec::new();
+    let mut wildcards: Vec<&ParsedTransition> = Vec::new();
     for transition in &parsed_fsm.transitions {
-        input_alphabet.insert(transition.input);
+        match &transition.input {
+            ParsedInput::Chars(chars) => {
+                for c in chars {
+                    explicit.push((*c, transition));
+                }
+            }
+            ParsedInput::Wildcard => wildcards.push(transition),
+        }
+    }
+
+    let mut input_alphabet: HashSet<char> = HashSet::new();
+    for (c, _) in &explicit {
+        input_alphabet.insert(*c);
     }
 
     for input_character in input_alphabet.to_owned() {
         for state in states.to_owned() {
-            let found: Vec<&ParsedTransition> = parsed_fsm
-                .transitions
+            let found: Vec<&ParsedTransition> = explicit
                 .iter()
-                .filter(|t| t.input == input_character && t.start_state == state.get_name())
+                .filter(|(c, t)| *c == input_character && t.start_state == state.get_name())
+                .map(|(_, t)| *t)
                 .collect();
-            match found.len() {
+
+            let chosen = match found.len() {
                 0 => {
-                    return Err(FSMError::MissingTransition(
-                        input_character,
-                        state.to_owned(),
-                    ));
-                }
-                1 => {
-                    let end_state_name = found.first().unwrap().end_state.to_owned();
-                    if let Some(end_state) = states
+                    let matching_wildcards: Vec<&ParsedTransition> = wildcards
                         .iter()
-                        .filter(|state| match state {
-                            State::State(name) => name == &end_state_name,
-                            State::AcceptState(name) => name == &end_state_name,
-                        })
-                        .collect::<Vec<&State>>()
-                        .first()
-                    {
-                        transitions.insert(Transition {
-                            input: input_character,
-                            start_state: state.to_owned(),
-                            end_state: end_state.to_owned().to_owned(),
-                        });
-                    } else {
-                        return Err(FSMError::UnknownState(end_state_name));
+                        .filter(|t| t.start_state == state.get_name())
+                        .copied()
+                        .collect();
+                    match matching_wildcards.len() {
+                        0 => {
+                            return Err(FSMError::MissingTransition(
+                                input_character,
+                                state.to_owned(),
+                            ));
+                        }
+                        1 => *matching_wildcards.first().unwrap(),
+                        2.. => {
+                            return Err(FSMError::ExtraTransition(
+                                matching_wildcards.get(0).unwrap().to_owned().to_owned(),
+                                matching_wildcards.get(1).unwrap().to_owned().to_owned(),
+                            ));
+                        }
                     }
                 }
+                1 => *found.first().unwrap(),
                 2.. => {
                     return Err(FSMError::ExtraTransition(
                         found.get(0).unwrap().to_owned().to_owned(),
                         found.get(1).unwrap().to_owned().to_owned(),
                     ));
                 }
+            };
+
+            let end_state_name = chosen.end_state.to_owned();
+            if let Some(end_state) = states
+                .iter()
+                .filter(|state| match state {
+                    State::State(name) => name == &end_state_name,
+                    State::AcceptState(name) => name == &end_state_name,
+                })
+                .collect::<Vec<&State>>()
+                .first()
+            {
+                transitions.insert(Transition {
+                    input: input_character,
+                    start_state: state.to_owned(),
+                    end_state: end_state.to_owned().to_owned(),
+                });
+            } else {
+                return Err(FSMError::UnknownState(end_state_name));
             }
         }
     }
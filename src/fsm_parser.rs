@@ -1,28 +1,60 @@
+use crate::parse_error::VerboseError;
 use nom::{
     IResult, Parser,
-    branch::permutation,
+    branch::{alt, permutation},
     bytes::complete::{is_a, is_not, tag},
     character::complete::{char, none_of},
     combinator::opt,
-    multi::{many0, many1},
+    error::context,
+    multi::{many0, many1, separated_list0},
 };
 use std::fmt::{self, Display, Formatter};
 
+/// The error type threaded through every parser in the crate; carries the
+/// `context()` labels needed to render human-readable parse errors.
+pub(crate) type PResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+
 #[derive(Debug)]
 pub enum ParsedState {
     State(String),
     AcceptState(String),
 }
 
+/// The input side of a transition line: one or more alternated characters,
+/// or `*` to match any symbol in the alphabet not otherwise matched from
+/// that state.
+#[derive(Debug, Clone)]
+pub enum ParsedInput {
+    Chars(Vec<char>),
+    Wildcard,
+}
+
+impl Display for ParsedInput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Chars(chars) => write!(
+                f,
+                "{}",
+                chars
+                    .iter()
+                    .map(char::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            ),
+            Self::Wildcard => write!(f, "*"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ParsedTransition {
-    pub input: char,
+    pub input: ParsedInput,
     pub start_state: String,
     pub end_state: String,
 }
 
 impl Display for ParsedTransition {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "{}: {} -> {}",
@@ -38,57 +70,106 @@ pub struct ParsedFSM {
     pub transitions: Vec<ParsedTransition>,
 }
 
-fn line_parser(i: &str) -> IResult<&str, ()> {
+pub(crate) fn line_parser<'a>(i: &'a str) -> PResult<'a, ()> {
     let (i, _) = many0(is_a("\r\n\0")).parse(i)?;
     Ok((i, ()))
 }
 
-fn blank_space_parser(i: &str) -> IResult<&str, ()> {
+pub(crate) fn blank_space_parser<'a>(i: &'a str) -> PResult<'a, ()> {
     let (i, _) = many0(is_a(" \t")).parse(i)?;
     Ok((i, ()))
 }
 
-fn state_name_parser(i: &str) -> IResult<&str, String> {
-    let (i, name) = (is_not(" \t\r\n:")).parse(i)?;
+pub(crate) fn state_name_parser<'a>(i: &'a str) -> PResult<'a, String> {
+    let (i, name) = context("state name", is_not(" \t\r\n:")).parse(i)?;
     Ok((i, String::from(name)))
 }
 
-fn state_parser(i: &str) -> IResult<&str, ParsedState> {
+fn state_parser<'a>(i: &'a str) -> PResult<'a, ParsedState> {
     if let Ok((i, Some(_))) =
-        (opt((char::<&str, nom::error::Error<&str>>('\t'), tag("final:")))).parse(i)
+        (opt((char::<&str, VerboseError<&str>>('\t'), tag("final:")))).parse(i)
     {
         let (i, _) = blank_space_parser(i)?;
         let (i, name) = state_name_parser(i)?;
         let (i, _) = line_parser(i)?;
         Ok((i, ParsedState::AcceptState(String::from(name))))
     } else {
-        let (i, _) = char('\t')(i)?;
+        let (i, _) = context("state", char('\t')).parse(i)?;
         let (i, name) = state_name_parser(i)?;
         let (i, _) = line_parser(i)?;
         Ok((i, ParsedState::State(String::from(name))))
     }
 }
 
-fn state_block_parser(i: &str) -> IResult<&str, Vec<ParsedState>> {
-    let (i, _) = (line_parser, tag("states:"), line_parser).parse(i)?;
+pub(crate) fn state_block_parser<'a>(i: &'a str) -> PResult<'a, Vec<ParsedState>> {
+    let (i, _) = (
+        line_parser,
+        context("'states:' block", tag("states:")),
+        line_parser,
+    )
+        .parse(i)?;
     many0(state_parser).parse(i)
 }
 
-fn input_char_parser(i: &str) -> IResult<&str, char> {
+pub(crate) fn input_char_parser<'a>(i: &'a str) -> PResult<'a, char> {
     let (i, (_, c, _, _)) = (
         blank_space_parser,
-        none_of(" \t\r\n:"),
-        char(':'),
+        context("input symbol", none_of(" \t\r\n:")),
+        context("':'", char(':')),
         blank_space_parser,
     )
         .parse(i)?;
     Ok((i, c))
 }
 
-fn transition_parser(i: &str) -> IResult<&str, ParsedTransition> {
-    let (i, input) = input_char_parser(i)?;
+fn alternated_input_char_parser<'a>(i: &'a str) -> PResult<'a, char> {
+    let (i, _) = blank_space_parser(i)?;
+    context("input symbol", none_of(" \t\r\n:|*")).parse(i)
+}
+
+fn alternation_separator_parser<'a>(i: &'a str) -> PResult<'a, char> {
+    let (i, _) = blank_space_parser(i)?;
+    char('|').parse(i)
+}
+
+fn transition_input_parser<'a>(i: &'a str) -> PResult<'a, ParsedInput> {
+    let (i, _) = blank_space_parser(i)?;
+    let (i, input) = context(
+        "input symbol, '|'-separated symbols, or '*'",
+        alt((
+            |i| {
+                let (i, _) = char('*')(i)?;
+                Ok((i, ParsedInput::Wildcard))
+            },
+            |i| {
+                let (i, chars) = separated_list0(
+                    alternation_separator_parser,
+                    alternated_input_char_parser,
+                )
+                .parse(i)?;
+                Ok((i, ParsedInput::Chars(chars)))
+            },
+        )),
+    )
+    .parse(i)?;
+    let (i, _) = (
+        blank_space_parser,
+        context("':'", char(':')),
+        blank_space_parser,
+    )
+        .parse(i)?;
+    Ok((i, input))
+}
+
+fn transition_parser<'a>(i: &'a str) -> PResult<'a, ParsedTransition> {
+    let (i, input) = transition_input_parser(i)?;
     let (i, start_state) = state_name_parser(i)?;
-    let (i, _) = (blank_space_parser, tag("->"), blank_space_parser).parse(i)?;
+    let (i, _) = (
+        blank_space_parser,
+        context("'->'", tag("->")),
+        blank_space_parser,
+    )
+        .parse(i)?;
     let (i, end_state) = state_name_parser(i)?;
     let (i, _) = line_parser(i)?;
     Ok((
@@ -101,17 +182,27 @@ fn transition_parser(i: &str) -> IResult<&str, ParsedTransition> {
     ))
 }
 
-fn transitions_block_parser(i: &str) -> IResult<&str, Vec<ParsedTransition>> {
-    let (i, _) = (line_parser, tag("transitions:"), line_parser).parse(i)?;
+fn transitions_block_parser<'a>(i: &'a str) -> PResult<'a, Vec<ParsedTransition>> {
+    let (i, _) = (
+        line_parser,
+        context("'transitions:' block", tag("transitions:")),
+        line_parser,
+    )
+        .parse(i)?;
     many1(transition_parser).parse(i)
 }
 
-fn start_block_parser(i: &str) -> IResult<&str, String> {
-    let (i, _) = (line_parser, tag("start:"), blank_space_parser).parse(i)?;
+pub(crate) fn start_block_parser<'a>(i: &'a str) -> PResult<'a, String> {
+    let (i, _) = (
+        line_parser,
+        context("'start:' block", tag("start:")),
+        blank_space_parser,
+    )
+        .parse(i)?;
     state_name_parser(i)
 }
 
-fn definition_parser(i: &str) -> IResult<&str, ParsedFSM> {
+fn definition_parser<'a>(i: &'a str) -> PResult<'a, ParsedFSM> {
     let (i, (start_state, states, transitions)) = permutation((
         start_block_parser,
         state_block_parser,
@@ -129,7 +220,7 @@ fn definition_parser(i: &str) -> IResult<&str, ParsedFSM> {
 }
 
 impl ParsedFSM {
-    pub fn parse(i: &str) -> IResult<&str, ParsedFSM> {
+    pub fn parse<'a>(i: &'a str) -> PResult<'a, ParsedFSM> {
         definition_parser(i)
     }
 }
@@ -2,10 +2,16 @@
 #![deny(elided_named_lifetimes)]
 #![deny(elided_lifetimes_in_associated_constant)]
 
+mod compiler;
 mod fsm;
 mod fsm_parser;
+mod minimize;
+mod nfa;
+mod parse_error;
+mod turing;
+mod turing_parser;
 
-use crate::{fsm::*, fsm_parser::*};
+use crate::{fsm::*, fsm_parser::*, nfa::*, turing::*, turing_parser::*};
 use clap::Parser;
 use color_eyre::Result;
 use std::{fs, path::PathBuf};
@@ -17,8 +23,40 @@ struct Cli {
     #[arg(short, long)]
     fsm_file: PathBuf,
 
-    #[arg(short, long)]
-    input_string: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Run the FSM against an input string
+    Run {
+        #[arg(short, long)]
+        input_string: String,
+
+        /// Parse and simulate the FSM file as a nondeterministic automaton
+        #[arg(long)]
+        nfa: bool,
+    },
+    /// Emit standalone Rust source implementing the FSM
+    Compile {
+        /// Where to write the generated source; prints to stdout if omitted
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+    /// Run as a tape-based Turing machine, printing the final tape contents
+    Tape {
+        #[arg(short, long)]
+        input_string: String,
+    },
+    /// Minimize the FSM and print it back in the crate's text format
+    Minimize,
+    /// Check whether two FSM files recognize the same language
+    Equiv {
+        /// Path to the second FSM file to compare against
+        #[arg(short, long)]
+        other_fsm_file: PathBuf,
+    },
 }
 
 fn main() -> Result<()> {
@@ -26,13 +64,61 @@ fn main() -> Result<()> {
 
     let fsm_string: String = fs::read_to_string(args.fsm_file)?;
     // "states:\n\tA\n\tB\n\tfinal: C\n\ntransitions:\n\t0: A -> B\n\t0: B -> C\n\t0: C -> A\n\t1: B -> A\n\t1: C -> B\n\t1: A -> C\n\nstart: A",
-    match ParsedFSM::parse(&fsm_string) {
-        Ok((_, parsed_fsm)) => {
-            let fsm = validate_parsed_fsm(parsed_fsm)?;
-
-            println!("{}", fsm.run(args.input_string.trim_end().to_string())?);
+    match args.command {
+        Command::Run { input_string, nfa } if nfa => match ParsedFSM::parse(&fsm_string) {
+            Ok((_, parsed_fsm)) => {
+                let nfa = validate_parsed_nfa(parsed_fsm)?;
+                println!("{}", nfa.run(input_string.trim_end())?);
+            }
+            Err(e) => println!("{}", parse_error::report(&fsm_string, &e)),
+        },
+        Command::Run { input_string, .. } => match ParsedFSM::parse(&fsm_string) {
+            Ok((_, parsed_fsm)) => {
+                let fsm = validate_parsed_fsm(parsed_fsm)?;
+                println!("{}", fsm.run(input_string.trim_end().to_string())?);
+            }
+            Err(e) => println!("{}", parse_error::report(&fsm_string, &e)),
+        },
+        Command::Compile { out } => match ParsedFSM::parse(&fsm_string) {
+            Ok((_, parsed_fsm)) => {
+                let fsm = validate_parsed_fsm(parsed_fsm)?;
+                let source = compiler::generate(&fsm);
+                match out {
+                    Some(path) => fs::write(path, source)?,
+                    None => print!("{}", source),
+                }
+            }
+            Err(e) => println!("{}", parse_error::report(&fsm_string, &e)),
+        },
+        Command::Tape { input_string } => match ParsedTuringMachine::parse(&fsm_string) {
+            Ok((_, parsed_tm)) => {
+                let tm = validate_parsed_tm(parsed_tm)?;
+                println!("{}", tm.run(input_string.trim_end().to_string())?);
+            }
+            Err(e) => println!("{}", parse_error::report(&fsm_string, &e)),
+        },
+        Command::Minimize => match ParsedFSM::parse(&fsm_string) {
+            Ok((_, parsed_fsm)) => {
+                let fsm = validate_parsed_fsm(parsed_fsm)?;
+                print!("{}", minimize::minimize(&fsm).render());
+            }
+            Err(e) => println!("{}", parse_error::report(&fsm_string, &e)),
+        },
+        Command::Equiv { other_fsm_file } => {
+            let other_fsm_string: String = fs::read_to_string(other_fsm_file)?;
+            match (
+                ParsedFSM::parse(&fsm_string),
+                ParsedFSM::parse(&other_fsm_string),
+            ) {
+                (Ok((_, a)), Ok((_, b))) => {
+                    let a = minimize::minimize(&validate_parsed_fsm(a)?);
+                    let b = minimize::minimize(&validate_parsed_fsm(b)?);
+                    println!("{}", minimize::isomorphic(&a, &b));
+                }
+                (Err(e), _) => println!("{}", parse_error::report(&fsm_string, &e)),
+                (_, Err(e)) => println!("{}", parse_error::report(&other_fsm_string, &e)),
+            }
         }
-        Err(e) => println!("{}", e),
     };
 
     Ok(())
@@ -0,0 +1,240 @@
+use crate::{
+    fsm::{FSM, State},
+    fsm_parser::{ParsedInput, ParsedTransition},
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Write as _,
+};
+
+/// A DFA reduced to its minimal form: each state is named after the
+/// `_`-joined, sorted set of original state names it absorbed.
+pub struct MinimizedFsm {
+    start: String,
+    states: Vec<(String, bool)>,
+    alphabet: Vec<char>,
+    transitions: HashMap<(String, char), String>,
+}
+
+impl MinimizedFsm {
+    fn is_accept(&self, name: &str) -> bool {
+        self.states
+            .iter()
+            .find(|(n, _)| n == name)
+            .is_some_and(|(_, accept)| *accept)
+    }
+
+    /// Renders the minimized machine back in the crate's own text format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "start: {}", self.start).unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(out, "states:").unwrap();
+        let mut names: Vec<&String> = self.states.iter().map(|(name, _)| name).collect();
+        names.sort();
+        for name in names {
+            if self.is_accept(name) {
+                writeln!(out, "\tfinal: {}", name).unwrap();
+            } else {
+                writeln!(out, "\t{}", name).unwrap();
+            }
+        }
+        writeln!(out).unwrap();
+
+        writeln!(out, "transitions:").unwrap();
+        let mut keys: Vec<&(String, char)> = self.transitions.keys().collect();
+        keys.sort();
+        for key in keys {
+            let parsed = ParsedTransition {
+                input: ParsedInput::Chars(vec![key.1]),
+                start_state: key.0.to_owned(),
+                end_state: self.transitions[key].to_owned(),
+            };
+            writeln!(out, "\t{}", parsed).unwrap();
+        }
+
+        out
+    }
+}
+
+/// Reduces `fsm` to its minimal equivalent via partition refinement: start
+/// from the partition {accept states, non-accept states} restricted to the
+/// states reachable from the start state, then repeatedly split any block
+/// whose members disagree on which block their transitions land in for some
+/// symbol, until no block splits further.
+pub fn minimize(fsm: &FSM) -> MinimizedFsm {
+    let mut alphabet: Vec<char> = fsm.input_alphabet().iter().copied().collect();
+    alphabet.sort();
+
+    let reachable = reachable_states(fsm);
+    let mut partition: Vec<HashSet<State>> = vec![
+        reachable
+            .iter()
+            .filter(|state| state.is_accept())
+            .cloned()
+            .collect(),
+        reachable
+            .iter()
+            .filter(|state| !state.is_accept())
+            .cloned()
+            .collect(),
+    ];
+    partition.retain(|block| !block.is_empty());
+
+    loop {
+        let mut refined: Vec<HashSet<State>> = Vec::new();
+        for block in &partition {
+            refined.extend(split(block, &partition, fsm, &alphabet));
+        }
+        let converged = refined.len() == partition.len();
+        partition = refined;
+        if converged {
+            break;
+        }
+    }
+
+    let block_name = |block: &HashSet<State>| -> String {
+        let mut members: Vec<&str> = block.iter().map(State::name).collect();
+        members.sort();
+        members.join("_")
+    };
+
+    let states: Vec<(String, bool)> = partition
+        .iter()
+        .map(|block| {
+            let name = block_name(block);
+            let accept = block.iter().any(State::is_accept);
+            (name, accept)
+        })
+        .collect();
+
+    let block_containing = |state: &State| -> &HashSet<State> {
+        partition
+            .iter()
+            .find(|block| block.contains(state))
+            .expect("every state belongs to exactly one block")
+    };
+
+    let start = block_name(block_containing(fsm.start_state()));
+
+    let mut transitions = HashMap::new();
+    for block in &partition {
+        let representative = block.iter().next().expect("blocks are never empty");
+        for c in &alphabet {
+            let target = target_of(fsm, representative, *c);
+            transitions.insert(
+                (block_name(block), *c),
+                block_name(block_containing(target)),
+            );
+        }
+    }
+
+    MinimizedFsm {
+        start,
+        states,
+        alphabet,
+        transitions,
+    }
+}
+
+/// States reachable from `fsm`'s start state. Complete-but-unreachable states
+/// must be excluded: keeping them around would make `minimize` emit a
+/// non-minimal machine and `equiv` report non-equivalence for two FSMs that
+/// agree on every string their start states can actually reach.
+fn reachable_states(fsm: &FSM) -> HashSet<State> {
+    let mut reachable = HashSet::new();
+    let mut pending = vec![fsm.start_state().to_owned()];
+    reachable.insert(fsm.start_state().to_owned());
+
+    while let Some(state) = pending.pop() {
+        for transition in fsm.transitions() {
+            if transition.start_state() == &state {
+                let target = transition.end_state().to_owned();
+                if reachable.insert(target.clone()) {
+                    pending.push(target);
+                }
+            }
+        }
+    }
+
+    reachable
+}
+
+fn target_of<'a>(fsm: &'a FSM, state: &State, symbol: char) -> &'a State {
+    fsm.transitions()
+        .iter()
+        .find(|t| t.start_state() == state && t.input() == symbol)
+        .map(|t| t.end_state())
+        .expect("validated FSMs have a transition for every state and alphabet symbol")
+}
+
+fn split(
+    block: &HashSet<State>,
+    partition: &[HashSet<State>],
+    fsm: &FSM,
+    alphabet: &[char],
+) -> Vec<HashSet<State>> {
+    let mut groups: HashMap<Vec<Option<usize>>, HashSet<State>> = HashMap::new();
+    for state in block {
+        let signature: Vec<Option<usize>> = alphabet
+            .iter()
+            .map(|c| {
+                let target = target_of(fsm, state, *c);
+                partition.iter().position(|b| b.contains(target))
+            })
+            .collect();
+        groups.entry(signature).or_default().insert(state.to_owned());
+    }
+    groups.into_values().collect()
+}
+
+/// Checks whether two minimized machines recognize the same language by
+/// walking both simultaneously from their start states and verifying the
+/// visited states form a consistent bijection.
+pub fn isomorphic(a: &MinimizedFsm, b: &MinimizedFsm) -> bool {
+    let mut alphabet_a = a.alphabet.clone();
+    let mut alphabet_b = b.alphabet.clone();
+    alphabet_a.sort();
+    alphabet_b.sort();
+    if alphabet_a != alphabet_b {
+        return false;
+    }
+
+    let mut forward: HashMap<String, String> = HashMap::new();
+    let mut backward: HashMap<String, String> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    forward.insert(a.start.clone(), b.start.clone());
+    backward.insert(b.start.clone(), a.start.clone());
+    queue.push_back((a.start.clone(), b.start.clone()));
+
+    while let Some((sa, sb)) = queue.pop_front() {
+        if a.is_accept(&sa) != b.is_accept(&sb) {
+            return false;
+        }
+
+        for c in &alphabet_a {
+            let ta = a.transitions.get(&(sa.clone(), *c));
+            let tb = b.transitions.get(&(sb.clone(), *c));
+            match (ta, tb) {
+                (Some(ta), Some(tb)) => match forward.get(ta) {
+                    Some(mapped) if mapped == tb => {}
+                    Some(_) => return false,
+                    None => {
+                        if backward.contains_key(tb) {
+                            return false;
+                        }
+                        forward.insert(ta.clone(), tb.clone());
+                        backward.insert(tb.clone(), ta.clone());
+                        queue.push_back((ta.clone(), tb.clone()));
+                    }
+                },
+                (None, None) => {}
+                _ => return false,
+            }
+        }
+    }
+
+    forward.len() == a.states.len() && backward.len() == b.states.len()
+}
@@ -0,0 +1,140 @@
+use crate::{fsm::State, fsm_parser::*};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display, Formatter},
+};
+
+#[derive(Debug)]
+pub struct NFA {
+    start_state: State,
+    transitions: HashMap<(State, Option<char>), HashSet<State>>,
+}
+
+impl NFA {
+    /// Simulates the NFA by tracking the set of currently active states,
+    /// computing an epsilon-closure after the start state and after every
+    /// input character, and accepting if that set ever contains an accept
+    /// state once the input is exhausted.
+    pub fn run(&self, input: &str) -> Result<bool, NFAError> {
+        let mut start = HashSet::new();
+        start.insert(self.start_state.to_owned());
+        let mut active = self.epsilon_closure(&start);
+
+        for c in input.chars() {
+            let mut next = HashSet::new();
+            for state in &active {
+                if let Some(targets) = self.transitions.get(&(state.to_owned(), Some(c))) {
+                    next.extend(targets.iter().cloned());
+                }
+            }
+            if next.is_empty() {
+                return Err(NFAError::MissingTransition(c));
+            }
+            active = self.epsilon_closure(&next);
+        }
+
+        Ok(active.iter().any(State::is_accept))
+    }
+
+    fn epsilon_closure(&self, states: &HashSet<State>) -> HashSet<State> {
+        let mut closure = states.to_owned();
+        let mut pending: Vec<State> = states.iter().cloned().collect();
+
+        while let Some(state) = pending.pop() {
+            if let Some(targets) = self.transitions.get(&(state, None)) {
+                for target in targets {
+                    if closure.insert(target.to_owned()) {
+                        pending.push(target.to_owned());
+                    }
+                }
+            }
+        }
+
+        closure
+    }
+}
+
+#[derive(Debug)]
+pub enum NFAError {
+    MissingTransition(char),
+    UnknownState(String),
+    NoStartState,
+    WildcardNotSupported,
+}
+
+impl std::error::Error for NFAError {}
+
+impl Display for NFAError {
+    fn fmt<'a>(&self, f: &mut Formatter<'a>) -> fmt::Result {
+        match self {
+            Self::MissingTransition(c) => {
+                write!(f, "No transition on '{}' leaves any active state", c)
+            }
+            Self::UnknownState(name) => write!(f, "Unknown state '{}'", name),
+            Self::NoStartState => write!(f, "No start state set"),
+            Self::WildcardNotSupported => write!(f, "'*' transitions are not supported in --nfa mode"),
+        }
+    }
+}
+
+/// Validates a parsed FSM as a nondeterministic automaton: unlike
+/// `validate_parsed_fsm`, multiple transitions per `(state, symbol)` are
+/// legal, and a transition with no listed symbols (or the literal `ε`) is
+/// treated as an epsilon edge.
+pub fn validate_parsed_nfa(parsed_fsm: ParsedFSM) -> Result<NFA, NFAError> {
+    let mut start_state = None;
+    let mut states = HashSet::new();
+
+    for state in parsed_fsm.states {
+        match state {
+            ParsedState::State(name) => {
+                states.insert(State::State(name.to_owned()));
+                if name == parsed_fsm.start_state {
+                    start_state = Some(State::State(name));
+                }
+            }
+            ParsedState::AcceptState(name) => {
+                states.insert(State::AcceptState(name.to_owned()));
+                if name == parsed_fsm.start_state {
+                    start_state = Some(State::AcceptState(name));
+                }
+            }
+        }
+    }
+
+    let mut transitions: HashMap<(State, Option<char>), HashSet<State>> = HashMap::new();
+    for transition in &parsed_fsm.transitions {
+        let start = states
+            .iter()
+            .find(|state| state.name() == transition.start_state)
+            .cloned()
+            .ok_or_else(|| NFAError::UnknownState(transition.start_state.to_owned()))?;
+        let end = states
+            .iter()
+            .find(|state| state.name() == transition.end_state)
+            .cloned()
+            .ok_or_else(|| NFAError::UnknownState(transition.end_state.to_owned()))?;
+
+        let symbols: Vec<Option<char>> = match &transition.input {
+            ParsedInput::Chars(chars) if chars.is_empty() || chars.as_slice() == ['ε'] => {
+                vec![None]
+            }
+            ParsedInput::Chars(chars) => chars.iter().map(|c| Some(*c)).collect(),
+            ParsedInput::Wildcard => return Err(NFAError::WildcardNotSupported),
+        };
+
+        for symbol in symbols {
+            transitions
+                .entry((start.to_owned(), symbol))
+                .or_default()
+                .insert(end.to_owned());
+        }
+    }
+
+    start_state
+        .map(|start_state| NFA {
+            start_state,
+            transitions,
+        })
+        .ok_or(NFAError::NoStartState)
+}
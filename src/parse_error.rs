@@ -0,0 +1,126 @@
+use nom::error::{ContextError, ErrorKind, ParseError};
+
+/// What a single parser frame reported about why it failed, recorded in the
+/// order the failure bubbled up through `context()`.
+#[derive(Debug, Clone)]
+pub(crate) enum VerboseErrorKind {
+    Context(&'static str),
+    Char(char),
+    Nom(ErrorKind),
+}
+
+/// Accumulates one `VerboseErrorKind` per parser frame a failure passed
+/// through, innermost first; nom dropped its own type of the same name, so
+/// this reimplements just enough of it for `context()` to thread labels here.
+#[derive(Debug, Clone)]
+pub(crate) struct VerboseError<I> {
+    pub errors: Vec<(I, VerboseErrorKind)>,
+}
+
+impl<I> ParseError<I> for VerboseError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        VerboseError {
+            errors: vec![(input, VerboseErrorKind::Nom(kind))],
+        }
+    }
+
+    fn append(input: I, kind: ErrorKind, mut other: Self) -> Self {
+        other.errors.push((input, VerboseErrorKind::Nom(kind)));
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        VerboseError {
+            errors: vec![(input, VerboseErrorKind::Char(c))],
+        }
+    }
+}
+
+impl<I> ContextError<I> for VerboseError<I> {
+    fn add_context(input: I, ctx: &'static str, mut other: Self) -> Self {
+        other.errors.push((input, VerboseErrorKind::Context(ctx)));
+        other
+    }
+}
+
+/// Renders a parse failure as the offending source line with a `^` caret
+/// under the first byte that could not be parsed, plus a short description
+/// of what was expected there.
+pub fn report(source: &str, error: &nom::Err<VerboseError<&str>>) -> String {
+    let verbose = match error {
+        nom::Err::Error(e) | nom::Err::Failure(e) => e,
+        nom::Err::Incomplete(_) => return "unexpected end of input".to_string(),
+    };
+
+    let Some((remaining, kind)) = select_frame(&verbose.errors) else {
+        return "failed to parse input".to_string();
+    };
+
+    let offset = byte_offset(source, remaining);
+    let (line_no, col_no, line_text) = locate(source, offset);
+    let caret_prefix: String = line_text
+        .chars()
+        .take(col_no.saturating_sub(1))
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    format!(
+        "parse error at line {}, column {}: {}\n{}\n{}^",
+        line_no,
+        col_no,
+        describe(kind),
+        line_text,
+        caret_prefix,
+    )
+}
+
+/// `errors.first()` is always the innermost leaf frame (`from_error_kind`/
+/// `from_char`), since `context()` pushes its label only once that inner
+/// parser has already failed and the error is unwinding outward. Prefer the
+/// `context()` frame nearest that same failure point instead, so the message
+/// uses the human label ("expected '->'") rather than the raw nom error kind
+/// ("unexpected input (Tag)").
+fn select_frame<'a, 'b>(
+    errors: &'b [(&'a str, VerboseErrorKind)],
+) -> Option<&'b (&'a str, VerboseErrorKind)> {
+    errors
+        .iter()
+        .filter(|(_, kind)| matches!(kind, VerboseErrorKind::Context(_)))
+        .min_by_key(|(remaining, _)| remaining.len())
+        .or_else(|| errors.iter().min_by_key(|(remaining, _)| remaining.len()))
+}
+
+fn byte_offset(source: &str, remaining: &str) -> usize {
+    (remaining.as_ptr() as usize).saturating_sub(source.as_ptr() as usize)
+}
+
+fn locate(source: &str, offset: usize) -> (usize, usize, &str) {
+    let mut line_no = 1;
+    let mut line_start = 0;
+    for (i, b) in source.bytes().enumerate() {
+        if i >= offset {
+            break;
+        }
+        if b == b'\n' {
+            line_no += 1;
+            line_start = i + 1;
+        }
+    }
+
+    let line_end = source[line_start..]
+        .find('\n')
+        .map(|end| line_start + end)
+        .unwrap_or(source.len());
+    let line_text = &source[line_start..line_end];
+    let col_no = source[line_start..offset.min(source.len())].chars().count() + 1;
+
+    (line_no, col_no, line_text)
+}
+
+fn describe(kind: &VerboseErrorKind) -> String {
+    match kind {
+        VerboseErrorKind::Context(label) => format!("expected {}", label),
+        VerboseErrorKind::Char(c) => format!("expected '{}'", c),
+        VerboseErrorKind::Nom(kind) => format!("unexpected input ({:?})", kind),
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{fsm::State, fsm_parser::ParsedState, turing_parser::*};
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// Symbol written onto cells the head has not yet visited.
+const BLANK: char = '␣';
+
+#[derive(Debug, Eq, Clone)]
+struct TMTransition {
+    input: char,
+    start_state: State,
+    actions: Vec<TapeAction>,
+    end_state: State,
+}
+
+impl PartialEq for TMTransition {
+    fn eq(&self, other: &Self) -> bool {
+        self.input == other.input && self.start_state == other.start_state
+    }
+}
+
+impl Hash for TMTransition {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.input.hash(state);
+        self.start_state.hash(state);
+    }
+}
+
+#[derive(Debug)]
+pub struct TuringMachine {
+    start_state: State,
+    transitions: HashSet<TMTransition>,
+}
+
+impl TuringMachine {
+    /// Runs the machine on `input` until a halt (accept) state is reached and
+    /// returns the tape contents, trimmed of trailing blanks.
+    pub fn run(&self, input: String) -> Result<String, TMError> {
+        let mut tape: Vec<char> = input.chars().collect();
+        if tape.is_empty() {
+            tape.push(BLANK);
+        }
+        let mut head = 0usize;
+        let mut current_state = self.start_state.to_owned();
+
+        while !current_state.is_accept() {
+            let symbol = tape[head];
+            let transition_pattern = TMTransition {
+                input: symbol,
+                start_state: current_state.to_owned(),
+                actions: Vec::new(),
+                end_state: State::State(String::new()),
+            };
+            let transition = self
+                .transitions
+                .get(&transition_pattern)
+                .ok_or_else(|| TMError::MissingTransition(symbol, current_state.to_owned()))?;
+
+            for action in &transition.actions {
+                match action {
+                    TapeAction::Write(c) => tape[head] = *c,
+                    TapeAction::Left => {
+                        if head == 0 {
+                            tape.insert(0, BLANK);
+                        } else {
+                            head -= 1;
+                        }
+                    }
+                    TapeAction::Right => {
+                        head += 1;
+                        if head == tape.len() {
+                            tape.push(BLANK);
+                        }
+                    }
+                }
+            }
+
+            current_state = transition.end_state.to_owned();
+        }
+
+        Ok(tape
+            .into_iter()
+            .collect::<String>()
+            .trim_end_matches(BLANK)
+            .to_string())
+    }
+}
+
+#[derive(Debug)]
+pub enum TMError {
+    MissingTransition(char, State),
+    ExtraTransition(ParsedTMTransition, ParsedTMTransition),
+    UnknownState(String),
+    NoStartState,
+}
+
+impl std::error::Error for TMError {}
+
+impl Display for TMError {
+    fn fmt<'a>(&self, f: &mut Formatter<'a>) -> fmt::Result {
+        match self {
+            Self::MissingTransition(c, state) => {
+                write!(f, "Missing transition on '{}' from {}", c, state)
+            }
+            Self::ExtraTransition(a, b) => write!(f, "Transition {} and {} conflict", a, b),
+            Self::UnknownState(name) => write!(f, "Unknown state '{}'", name),
+            Self::NoStartState => write!(f, "No start state set"),
+        }
+    }
+}
+
+pub fn validate_parsed_tm(parsed_tm: ParsedTuringMachine) -> Result<TuringMachine, TMError> {
+    let mut start_state = None;
+    let mut states = HashSet::new();
+
+    for state in parsed_tm.states {
+        match state {
+            ParsedState::State(name) => {
+                states.insert(State::State(name.to_owned()));
+                if name == parsed_tm.start_state {
+                    start_state = Some(State::State(name));
+                }
+            }
+            ParsedState::AcceptState(name) => {
+                states.insert(State::AcceptState(name.to_owned()));
+                if name == parsed_tm.start_state {
+                    start_state = Some(State::AcceptState(name));
+                }
+            }
+        }
+    }
+
+    let mut transitions = HashSet::new();
+    for transition in &parsed_tm.transitions {
+        let found: Vec<&ParsedTMTransition> = parsed_tm
+            .transitions
+            .iter()
+            .filter(|t| t.input == transition.input && t.start_state == transition.start_state)
+            .collect();
+        if found.len() > 1 {
+            return Err(TMError::ExtraTransition(
+                found[0].to_owned(),
+                found[1].to_owned(),
+            ));
+        }
+
+        let start_state = states
+            .iter()
+            .find(|state| state.name() == transition.start_state)
+            .cloned()
+            .ok_or_else(|| TMError::UnknownState(transition.start_state.to_owned()))?;
+        let end_state = states
+            .iter()
+            .find(|state| state.name() == transition.end_state)
+            .cloned()
+            .ok_or_else(|| TMError::UnknownState(transition.end_state.to_owned()))?;
+
+        transitions.insert(TMTransition {
+            input: transition.input,
+            start_state,
+            actions: transition.actions.to_owned(),
+            end_state,
+        });
+    }
+
+    start_state
+        .map(|start_state| TuringMachine {
+            start_state,
+            transitions,
+        })
+        .ok_or(TMError::NoStartState)
+}
@@ -0,0 +1,134 @@
+use crate::fsm_parser::{
+    PResult, ParsedState, blank_space_parser, input_char_parser, line_parser, start_block_parser,
+    state_block_parser, state_name_parser,
+};
+use nom::{
+    Parser,
+    branch::{alt, permutation},
+    bytes::complete::tag,
+    character::complete::{char, none_of},
+    multi::{many1, separated_list1},
+};
+use std::fmt::{self, Display, Formatter};
+
+/// A single action applied to the tape at the current head position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TapeAction {
+    Write(char),
+    Left,
+    Right,
+}
+
+impl Display for TapeAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Write(c) => write!(f, "P({})", c),
+            Self::Left => write!(f, "L"),
+            Self::Right => write!(f, "R"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedTMTransition {
+    pub input: char,
+    pub start_state: String,
+    pub actions: Vec<TapeAction>,
+    pub end_state: String,
+}
+
+impl Display for ParsedTMTransition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let actions = self
+            .actions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("-");
+        write!(
+            f,
+            "{}: {} -> {} -> {}",
+            self.input, self.start_state, actions, self.end_state
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ParsedTuringMachine {
+    pub start_state: String,
+    pub states: Vec<ParsedState>,
+    pub transitions: Vec<ParsedTMTransition>,
+}
+
+fn write_action_parser<'a>(i: &'a str) -> PResult<'a, TapeAction> {
+    let (i, _) = tag("P(").parse(i)?;
+    let (i, c) = none_of(")")(i)?;
+    let (i, _) = char(')').parse(i)?;
+    Ok((i, TapeAction::Write(c)))
+}
+
+fn tape_action_parser<'a>(i: &'a str) -> PResult<'a, TapeAction> {
+    alt((
+        write_action_parser,
+        |i| {
+            let (i, _) = char('L').parse(i)?;
+            Ok((i, TapeAction::Left))
+        },
+        |i| {
+            let (i, _) = char('R').parse(i)?;
+            Ok((i, TapeAction::Right))
+        },
+    ))
+    .parse(i)
+}
+
+fn actions_parser<'a>(i: &'a str) -> PResult<'a, Vec<TapeAction>> {
+    separated_list1(char('-'), tape_action_parser).parse(i)
+}
+
+fn tm_transition_parser<'a>(i: &'a str) -> PResult<'a, ParsedTMTransition> {
+    let (i, input) = input_char_parser(i)?;
+    let (i, start_state) = state_name_parser(i)?;
+    let (i, _) = (blank_space_parser, tag("->"), blank_space_parser).parse(i)?;
+    let (i, actions) = actions_parser(i)?;
+    let (i, _) = (blank_space_parser, tag("->"), blank_space_parser).parse(i)?;
+    let (i, end_state) = state_name_parser(i)?;
+    let (i, _) = line_parser(i)?;
+    Ok((
+        i,
+        ParsedTMTransition {
+            input,
+            start_state,
+            actions,
+            end_state,
+        },
+    ))
+}
+
+fn tm_transitions_block_parser<'a>(i: &'a str) -> PResult<'a, Vec<ParsedTMTransition>> {
+    let (i, _) = (line_parser, tag("transitions:"), line_parser).parse(i)?;
+    many1(tm_transition_parser).parse(i)
+}
+
+fn tm_definition_parser<'a>(i: &'a str) -> PResult<'a, ParsedTuringMachine> {
+    let (i, (start_state, states, transitions)) = permutation((
+        start_block_parser,
+        state_block_parser,
+        tm_transitions_block_parser,
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        ParsedTuringMachine {
+            start_state,
+            states,
+            transitions,
+        },
+    ))
+}
+
+impl ParsedTuringMachine {
+    pub fn parse<'a>(i: &'a str) -> PResult<'a, ParsedTuringMachine> {
+        tm_definition_parser(i)
+    }
+}